@@ -0,0 +1,143 @@
+// Copyright 2016-2018 Mateusz Sieczko and other GilRs Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::time::Duration;
+
+use windows::Foundation::Numerics::Vector3;
+use windows::Foundation::TimeSpan;
+use windows::Gaming::Input::ForceFeedback::{
+    ConstantForceEffect, ForceFeedbackEffectAxes, IForceFeedbackEffect, PeriodicForceEffect,
+    PeriodicForceEffectKind,
+};
+use windows::Gaming::Input::{
+    ForceFeedbackMotor, Gamepad as WgiGamepad, GamepadVibration, RawGameController,
+};
+
+fn duration_to_timespan(duration: Duration) -> TimeSpan {
+    TimeSpan {
+        Duration: duration.as_micros() as i64 * 10,
+    }
+}
+
+/// A force-feedback handle for a WGI controller, primarily driven through the
+/// [ForceFeedback](https://learn.microsoft.com/en-us/uwp/api/windows.gaming.input.forcefeedback)
+/// namespace, with [`FfDevice::set_basic_vibration`] available as a fallback to the plain
+/// `Gamepad::Vibration` rumble motors for controllers that don't expose any
+/// [`ForceFeedbackMotor`]s.
+///
+/// Each [`ForceFeedbackMotor`] can only have a single effect loaded at a time, so loading a new
+/// one (via [`FfDevice::play_constant_force`]/[`FfDevice::play_periodic`]) replaces whatever the
+/// motor was previously playing.
+#[derive(Debug, Clone)]
+pub struct FfDevice {
+    id: u32,
+    wgi_gamepad: Option<WgiGamepad>,
+    motors: Vec<ForceFeedbackMotor>,
+}
+
+impl FfDevice {
+    pub(crate) fn new(
+        id: u32,
+        raw_game_controller: RawGameController,
+        wgi_gamepad: Option<WgiGamepad>,
+    ) -> Self {
+        let motors = raw_game_controller
+            .ForceFeedbackMotors()
+            .map(|motors| motors.into_iter().collect())
+            .unwrap_or_default();
+
+        FfDevice {
+            id,
+            wgi_gamepad,
+            motors,
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the force-feedback motors on this device that support `axes`, e.g. so Xbox
+    /// trigger-rumble motors can be targeted separately from the main handles.
+    pub fn motors_supporting(&self, axes: ForceFeedbackEffectAxes) -> Vec<&ForceFeedbackMotor> {
+        self.motors
+            .iter()
+            .filter(|motor| motor.AreAxesSupported(axes).unwrap_or(false))
+            .collect()
+    }
+
+    /// Sets the master gain (0.0 - 1.0) applied to every effect played on every motor.
+    pub fn set_gain(&self, gain: f64) -> windows::core::Result<()> {
+        for motor in &self.motors {
+            motor.TrySetMasterGain(gain)?;
+        }
+        Ok(())
+    }
+
+    /// Loads and starts a constant-force effect on every motor, pushing in `direction` (a unit
+    /// vector scaled by magnitude) for `duration`.
+    pub fn play_constant_force(
+        &self,
+        direction: Vector3,
+        duration: Duration,
+    ) -> windows::core::Result<()> {
+        let effect = ConstantForceEffect::new()?;
+        effect.SetParameters(direction, duration_to_timespan(duration))?;
+        self.load_and_start(effect.cast()?)
+    }
+
+    /// Loads and starts a periodic (sine/square/triangle/sawtooth) effect on every motor.
+    ///
+    /// `repeat_count` lets the effect loop, matching the repeat/loop semantics of the WGI effect
+    /// types rather than always playing a single shot.
+    pub fn play_periodic(
+        &self,
+        kind: PeriodicForceEffectKind,
+        direction: Vector3,
+        frequency: f64,
+        period: Duration,
+        repeat_count: u32,
+    ) -> windows::core::Result<()> {
+        let effect = PeriodicForceEffect::new()?;
+        effect.SetParameters(
+            kind,
+            direction,
+            frequency,
+            0.0,
+            0.0,
+            duration_to_timespan(period),
+        )?;
+        effect.SetRepeatCount(repeat_count)?;
+        self.load_and_start(effect.cast()?)
+    }
+
+    /// Stops whatever effect is currently loaded on every motor.
+    pub fn stop(&self) -> windows::core::Result<()> {
+        for motor in &self.motors {
+            motor.Stop()?;
+        }
+        Ok(())
+    }
+
+    /// Drives the basic low-frequency/high-frequency (and, on Xbox pads, trigger) rumble motors
+    /// through `Gamepad::Vibration`, for devices this handle has no `ForceFeedbackMotor`s for —
+    /// a no-op if the controller wasn't recognized as a `Gamepad`.
+    pub fn set_basic_vibration(&self, vibration: GamepadVibration) -> windows::core::Result<()> {
+        match &self.wgi_gamepad {
+            Some(gamepad) => gamepad.SetVibration(vibration),
+            None => Ok(()),
+        }
+    }
+
+    fn load_and_start(&self, effect: IForceFeedbackEffect) -> windows::core::Result<()> {
+        for motor in &self.motors {
+            motor.TryLoadEffectAsync(&effect)?.get()?;
+            motor.Start()?;
+        }
+        Ok(())
+    }
+}
@@ -16,7 +16,10 @@ use std::time::{Duration, SystemTime};
 use std::{thread, u32};
 use windows::Foundation::EventHandler;
 use windows::Gaming::Input::RawGameController;
-use windows::Gaming::Input::{GameControllerSwitchPosition, Gamepad as WgiGamepad};
+use windows::Gaming::Input::{
+    ArcadeStick, FlightStick, GameControllerButtonLabel, GameControllerSwitchPosition,
+    Gamepad as WgiGamepad, RacingWheel,
+};
 
 const SDL_HARDWARE_BUS_USB: u32 = 0x03;
 const SDL_HARDWARE_BUS_BLUETOOTH: u32 = 0x05;
@@ -35,11 +38,19 @@ struct WgiEvent {
 }
 
 // Chosen by dice roll ;)
-const EVENT_THREAD_SLEEP_TIME: u64 = 10;
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(10);
 
 impl WgiEvent {
+    /// Connect/disconnect notifications don't come from a reading, so there's no hardware
+    /// timestamp to attach; stamp those with wall-clock time.
     fn new(raw_game_controller: RawGameController, event: EventType) -> Self {
-        let time = utils::time_now();
+        WgiEvent::with_time(raw_game_controller, event, utils::time_now())
+    }
+
+    /// Used for events synthesized from a `GamePadReading` diff, where `time` is the hardware
+    /// timestamp of the reading that produced them (see `reading_time_to_system_time`), not the
+    /// time the event thread happened to notice the change.
+    fn with_time(raw_game_controller: RawGameController, event: EventType, time: SystemTime) -> Self {
         WgiEvent {
             raw_game_controller,
             event,
@@ -48,94 +59,470 @@ impl WgiEvent {
     }
 }
 
+/// Converts a `GamePadReading::time`/reading `Timestamp` (a count of 100ns ticks on an
+/// arbitrary, per-controller monotonic clock) into wall-clock time, given an `anchor` pairing an
+/// earlier raw tick count with the `SystemTime` it was observed at.
+fn reading_time_to_system_time(anchor: (u64, SystemTime), raw_time: u64) -> SystemTime {
+    let (anchor_time, anchor_system_time) = anchor;
+    let ticks_elapsed = raw_time.saturating_sub(anchor_time);
+    anchor_system_time + Duration::from_nanos(ticks_elapsed.saturating_mul(100))
+}
+
 #[derive(Debug)]
 pub struct Gilrs {
     gamepads: Vec<Gamepad>,
     rx: Receiver<WgiEvent>,
 }
 
+/// Configuration for synthesizing a digital button from an analog axis, for controls (like WGI's
+/// trigger axes) that only ever report through `AxisValueChanged`.
+///
+/// `press_threshold` and `release_threshold` give the synthesized button hysteresis: once held,
+/// the axis has to fall back below `release_threshold` (rather than just `press_threshold`) to
+/// release it, so noise near a single threshold doesn't chatter the button.
+#[derive(Debug, Clone)]
+pub(crate) struct AxisToButtonConfig {
+    axis: EvCode,
+    button: EvCode,
+    press_threshold: f64,
+    release_threshold: f64,
+}
+
+impl AxisToButtonConfig {
+    pub(crate) fn new(
+        axis: EvCode,
+        button: EvCode,
+        press_threshold: f64,
+        release_threshold: f64,
+    ) -> Self {
+        AxisToButtonConfig {
+            axis,
+            button,
+            press_threshold,
+            release_threshold,
+        }
+    }
+
+    /// The axis-to-button layer gilrs ships with by default: the trigger axes many WGI pads only
+    /// expose as analog (`AXIS_LT2`/`AXIS_RT2`) synthesize their digital counterparts
+    /// (`BTN_LT2`/`BTN_RT2`).
+    fn defaults() -> Vec<Self> {
+        use native_ev_codes::*;
+        vec![
+            AxisToButtonConfig::new(AXIS_LT2, BTN_LT2, 0.75, 0.65),
+            AxisToButtonConfig::new(AXIS_RT2, BTN_RT2, 0.75, 0.65),
+        ]
+    }
+}
+
+/// The specialized WGI projections of a controller, cast once and reused for every poll rather
+/// than re-cast on each tick of the event thread.
+#[derive(Debug, Clone)]
+struct SpecializedController {
+    device_class: DeviceClass,
+    arcade_stick: Option<ArcadeStick>,
+    flight_stick: Option<FlightStick>,
+    racing_wheel: Option<RacingWheel>,
+}
+
+impl SpecializedController {
+    fn new(raw_game_controller: &RawGameController) -> Self {
+        let arcade_stick = ArcadeStick::FromGameController(raw_game_controller).ok();
+        let flight_stick = FlightStick::FromGameController(raw_game_controller).ok();
+        let racing_wheel = RacingWheel::FromGameController(raw_game_controller).ok();
+
+        let device_class = if racing_wheel.is_some() {
+            DeviceClass::RacingWheel
+        } else if flight_stick.is_some() {
+            DeviceClass::FlightStick
+        } else if arcade_stick.is_some() {
+            DeviceClass::ArcadeStick
+        } else if WgiGamepad::FromGameController(raw_game_controller).is_ok() {
+            DeviceClass::Gamepad
+        } else {
+            DeviceClass::RawController
+        };
+
+        SpecializedController {
+            device_class,
+            arcade_stick,
+            flight_stick,
+            racing_wheel,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct GamePadReading {
     axes: Vec<f64>,
     buttons: Vec<bool>,
     switches: Vec<GameControllerSwitchPosition>,
     time: u64,
+    /// Which WGI projection this reading came from, so the axis scaling and button-to-`EvCode`
+    /// mapping in `events_from_differences` can be specific to the reading shape that produced
+    /// it instead of assuming every axis is the generic raw-controller 0.0..1.0 range.
+    device_class: DeviceClass,
+    /// `RawGameController::ButtonCount`, fetched once here since it's a property of the hardware
+    /// rather than of a particular reading, so the specialized `update_with` branches don't have
+    /// to make a fresh COM call for it on every poll.
+    button_count: usize,
 }
 
+/// The 8 bits of an `ArcadeStick` reading's `Buttons` bitmask, in order, mapped to the native
+/// `EvCode`s advertised by `Gamepad::buttons()` for `DeviceClass::ArcadeStick` (see
+/// `collect_axes_and_buttons`) so the events `events_from_differences` emits for this device
+/// class actually match what callers enumerating `buttons()` expect to see fire.
+const ARCADE_STICK_BUTTONS: [EvCode; 8] = [
+    native_ev_codes::BTN_SOUTH,
+    native_ev_codes::BTN_EAST,
+    native_ev_codes::BTN_WEST,
+    native_ev_codes::BTN_NORTH,
+    native_ev_codes::BTN_C,
+    native_ev_codes::BTN_Z,
+    native_ev_codes::BTN_LT,
+    native_ev_codes::BTN_RT,
+];
+
 impl GamePadReading {
-    fn new(raw_game_controller: &RawGameController) -> windows::core::Result<Self> {
-        let axis_count = raw_game_controller.AxisCount()? as usize;
-        let button_count = raw_game_controller.ButtonCount()? as usize;
+    fn new(
+        raw_game_controller: &RawGameController,
+        device_class: DeviceClass,
+        arcade_stick: Option<&ArcadeStick>,
+        flight_stick: Option<&FlightStick>,
+        racing_wheel: Option<&RacingWheel>,
+    ) -> windows::core::Result<Self> {
         let switch_count = raw_game_controller.SwitchCount()? as usize;
-        let mut new = Self {
-            axes: vec![0.0; axis_count],
-            buttons: vec![false; button_count],
-            switches: vec![GameControllerSwitchPosition::default(); switch_count],
-            time: 0,
+        let button_count = raw_game_controller.ButtonCount()? as usize;
+
+        let mut new = match device_class {
+            DeviceClass::ArcadeStick | DeviceClass::FlightStick | DeviceClass::RacingWheel => {
+                Self {
+                    axes: Vec::new(),
+                    buttons: Vec::new(),
+                    switches: Vec::new(),
+                    time: 0,
+                    device_class,
+                    button_count,
+                }
+            }
+            DeviceClass::RawController | DeviceClass::Gamepad => {
+                let axis_count = raw_game_controller.AxisCount()? as usize;
+                Self {
+                    axes: vec![0.0; axis_count],
+                    buttons: vec![false; button_count],
+                    switches: vec![GameControllerSwitchPosition::default(); switch_count],
+                    time: 0,
+                    device_class,
+                    button_count,
+                }
+            }
         };
-        new.time = raw_game_controller.GetCurrentReading(
-            &mut new.buttons,
-            &mut new.switches,
-            &mut new.axes,
-        )?;
+        new.update_with(raw_game_controller, arcade_stick, flight_stick, racing_wheel)?;
         Ok(new)
     }
 
-    fn update(&mut self, raw_game_controller: &RawGameController) -> windows::core::Result<()> {
-        self.time = raw_game_controller.GetCurrentReading(
-            &mut self.buttons,
-            &mut self.switches,
-            &mut self.axes,
-        )?;
+    fn update(
+        &mut self,
+        raw_game_controller: &RawGameController,
+        arcade_stick: Option<&ArcadeStick>,
+        flight_stick: Option<&FlightStick>,
+        racing_wheel: Option<&RacingWheel>,
+    ) -> windows::core::Result<()> {
+        self.update_with(raw_game_controller, arcade_stick, flight_stick, racing_wheel)
+    }
+
+    /// Poll the most specific WGI projection available: the specialized reading types carry
+    /// semantically-labelled fields (wheel, throttle, hat switch, ...) that don't come through
+    /// `RawGameController::GetCurrentReading`'s anonymous axis/button arrays at all.
+    fn update_with(
+        &mut self,
+        raw_game_controller: &RawGameController,
+        arcade_stick: Option<&ArcadeStick>,
+        flight_stick: Option<&FlightStick>,
+        racing_wheel: Option<&RacingWheel>,
+    ) -> windows::core::Result<()> {
+        if let Some(racing_wheel) = racing_wheel {
+            let reading = racing_wheel.GetCurrentReading()?;
+            self.time = reading.Timestamp()?;
+            self.axes = vec![
+                reading.Wheel()?,
+                reading.Throttle()?,
+                reading.Brake()?,
+                reading.Clutch()?,
+                reading.Handbrake()?,
+                reading.PatternShifterGear()? as f64,
+            ];
+            let buttons = reading.WirelessButtons()?.0;
+            self.buttons = (0..self.button_count.min(32))
+                .map(|bit| buttons & (1 << bit) != 0)
+                .collect();
+        } else if let Some(flight_stick) = flight_stick {
+            let reading = flight_stick.GetCurrentReading()?;
+            self.time = reading.Timestamp()?;
+            self.axes = vec![
+                reading.Roll()?,
+                reading.Pitch()?,
+                reading.Rudder()?,
+                reading.Throttle()?,
+            ];
+            self.switches = vec![reading.HatSwitch()?];
+            let buttons = reading.Buttons()?.0;
+            self.buttons = (0..self.button_count.min(32))
+                .map(|bit| buttons & (1 << bit) != 0)
+                .collect();
+        } else if let Some(arcade_stick) = arcade_stick {
+            let reading = arcade_stick.GetCurrentReading()?;
+            self.time = reading.Timestamp()?;
+            let buttons = reading.Buttons()?.0;
+            self.buttons = (0..self.button_count.min(ARCADE_STICK_BUTTONS.len()))
+                .map(|bit| buttons & (1 << bit) != 0)
+                .collect();
+        } else {
+            self.time = raw_game_controller.GetCurrentReading(
+                &mut self.buttons,
+                &mut self.switches,
+                &mut self.axes,
+            )?;
+        }
         Ok(())
     }
 
     /// Create a list of event types that describe the differences from this reading to the
     /// provided new reading.
+    ///
+    /// Note that WGI's `RawGameController`/`Gamepad` surface only ever reports the latest state,
+    /// not a history of reports: if a button goes down and back up between two polls, the two
+    /// readings compared here look identical and nothing is emitted for it. There is no reliable
+    /// way to tell *which* button (if any) quietly round-tripped from this surface alone — emitting
+    /// a guessed press/release for every button that still looks unchanged after a slow poll would
+    /// manufacture a full-pad press/release burst on every scheduling hiccup, which is worse than
+    /// the miss it would be trying to paper over. Shortening the poll interval
+    /// (`Gilrs::new_with_poll_interval`) is the real mitigation for a fast press-and-release being
+    /// missed entirely.
+    ///
+    /// This means a round-tripped button still goes unreported: there is no sound per-button
+    /// "missed it" detection on this surface, only the hardware-timestamp event ordering below
+    /// (`reading_time_to_system_time`) and the poll-interval knob above. That's a deliberately
+    /// smaller guarantee than "never lose a fast press/release" — chasing the latter here would
+    /// mean guessing, which is what got reverted.
     fn events_from_differences(&self, new_reading: &Self) -> Vec<EventType> {
         let mut changed = Vec::new();
+        let device_class = new_reading.device_class;
 
-        // Axis changes
+        // Axis changes. The specialized device classes advertise named axes (see
+        // `collect_axes_and_buttons`) rather than their reading's positional indices.
+        let named_axes: &[EvCode] = {
+            use native_ev_codes::*;
+            match device_class {
+                DeviceClass::RacingWheel => &[
+                    AXIS_WHEEL,
+                    AXIS_THROTTLE,
+                    AXIS_BRAKE,
+                    AXIS_CLUTCH,
+                    AXIS_HANDBRAKE,
+                    AXIS_GEAR,
+                ],
+                DeviceClass::FlightStick => &[AXIS_ROLL, AXIS_PITCH, AXIS_RUDDER, AXIS_THROTTLE],
+                _ => &[],
+            }
+        };
         for index in 0..new_reading.axes.len() {
             if self.axes.get(index) != new_reading.axes.get(index) {
-                let value = (((new_reading.axes[index] - 0.5) * 2.0) * u16::MAX as f64) as i32;
-                let event = EventType::AxisValueChanged(
-                    value,
-                    crate::EvCode(EvCode {
-                        kind: EvCodeKind::Axis,
-                        index: index as u32,
-                    }),
-                );
+                let value = scale_axis_value(device_class, index, new_reading.axes[index]);
+                let code = named_axes.get(index).copied().unwrap_or(EvCode {
+                    kind: EvCodeKind::Axis,
+                    index: index as u32,
+                });
+                let event = EventType::AxisValueChanged(value, crate::EvCode(code));
                 changed.push(event);
             }
         }
         for index in 0..new_reading.buttons.len() {
-            if self.buttons.get(index) != new_reading.buttons.get(index) {
-                let event = match new_reading.buttons[index] {
-                    true => EventType::ButtonPressed(crate::EvCode(EvCode {
-                        kind: EvCodeKind::Button,
-                        index: index as u32,
-                    })),
-                    false => EventType::ButtonReleased(crate::EvCode(EvCode {
-                        kind: EvCodeKind::Button,
-                        index: index as u32,
-                    })),
+            let old_value = self.buttons.get(index).copied();
+            let new_value = new_reading.buttons.get(index).copied();
+            let code = crate::EvCode(button_ev_code(device_class, index));
+            match (old_value, new_value) {
+                (Some(old_value), Some(new_value)) if old_value != new_value => {
+                    changed.push(if new_value {
+                        EventType::ButtonPressed(code)
+                    } else {
+                        EventType::ButtonReleased(code)
+                    });
+                }
+                _ => {}
+            }
+        }
+        // Switches (POV hats / D-pads) don't map onto a single axis or button: a diagonal
+        // position holds down two directions at once. Decompose both the old and new position
+        // into up/right/down/left components and diff those instead, so a direct diagonal to
+        // diagonal transition correctly releases the direction that is no longer held.
+        for index in 0..new_reading.switches.len() {
+            let old_position = self.switches.get(index).copied().unwrap_or_default();
+            let new_position = match new_reading.switches.get(index) {
+                Some(position) => *position,
+                None => continue,
+            };
+            if old_position == new_position {
+                continue;
+            }
+            let old_directions = switch_position_to_directions(old_position);
+            let new_directions = switch_position_to_directions(new_position);
+
+            for direction in 0..4 {
+                if old_directions[direction] == new_directions[direction] {
+                    continue;
+                }
+                let code = crate::EvCode(EvCode {
+                    kind: EvCodeKind::Switch,
+                    index: index as u32 * 4 + direction as u32,
+                });
+                let event = if new_directions[direction] {
+                    EventType::ButtonPressed(code)
+                } else {
+                    EventType::ButtonReleased(code)
                 };
                 changed.push(event);
             }
         }
-        // Todo: Decide if this should be treated as a button or axis
-        // for index in 0..new_reading.switches.len() {
-        //     if self.switches.get(index) != new_reading.switches.get(index) {
-        //
-        //     }
-        // }
         changed
     }
+
+    /// Synthesizes button press/release events for the axes configured in `axis_to_button`,
+    /// using `states` (one hysteresis flag per entry, index-aligned with `axis_to_button`) to
+    /// remember whether each axis is currently considered held so a threshold crossing only
+    /// fires once instead of on every poll.
+    fn axis_to_button_events(
+        &self,
+        axis_to_button: &[AxisToButtonConfig],
+        states: &mut [bool],
+    ) -> Vec<EventType> {
+        let mut changed = Vec::new();
+        for (config, held) in axis_to_button.iter().zip(states.iter_mut()) {
+            let value = match self.axes.get(config.axis.index as usize) {
+                Some(value) => *value,
+                None => continue,
+            };
+            let new_held = if *held {
+                value > config.release_threshold
+            } else {
+                value >= config.press_threshold
+            };
+            if new_held == *held {
+                continue;
+            }
+            *held = new_held;
+            let code = crate::EvCode(config.button);
+            changed.push(if new_held {
+                EventType::ButtonPressed(code)
+            } else {
+                EventType::ButtonReleased(code)
+            });
+        }
+        changed
+    }
+}
+
+/// How an analog axis's raw WGI reading maps onto gilrs's signed `i32` axis value (full
+/// deflection is `±u16::MAX`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum AxisScale {
+    /// WGI's generic raw-controller/gamepad axis range: 0.0 (one extreme) .. 1.0 (the other),
+    /// centered on 0.5 and doubled out to the full signed range.
+    UnsignedUnit,
+    /// Already signed and full-range (-1.0..1.0), so it only needs to be scaled, not centered.
+    SignedUnit,
+    /// Not a continuous analog reading at all (`RacingWheelReading::PatternShifterGear`'s gear
+    /// number), so it's reported as the raw integer rather than normalized into the axis range.
+    Raw,
+}
+
+/// Picks the `AxisScale` for axis `index` of a reading from `device_class`, matching the axis
+/// order each device class's branch of `update_with` builds `self.axes` in.
+fn axis_scale(device_class: DeviceClass, index: usize) -> AxisScale {
+    match device_class {
+        // RacingWheelReading axes: [Wheel, Throttle, Brake, Clutch, Handbrake, PatternShifterGear]
+        DeviceClass::RacingWheel => match index {
+            0 => AxisScale::SignedUnit,
+            5 => AxisScale::Raw,
+            _ => AxisScale::UnsignedUnit,
+        },
+        // FlightStickReading axes: [Roll, Pitch, Rudder, Throttle]
+        DeviceClass::FlightStick => match index {
+            0 | 1 | 2 => AxisScale::SignedUnit,
+            _ => AxisScale::UnsignedUnit,
+        },
+        DeviceClass::ArcadeStick | DeviceClass::RawController | DeviceClass::Gamepad => {
+            AxisScale::UnsignedUnit
+        }
+    }
+}
+
+fn scale_axis_value(device_class: DeviceClass, index: usize, raw: f64) -> i32 {
+    match axis_scale(device_class, index) {
+        AxisScale::UnsignedUnit => (((raw - 0.5) * 2.0) * u16::MAX as f64) as i32,
+        AxisScale::SignedUnit => (raw * u16::MAX as f64) as i32,
+        AxisScale::Raw => raw as i32,
+    }
+}
+
+/// Maps a bit position in a reading's button array to the `EvCode` it actually represents.
+///
+/// For most device classes this is just the bit position itself, but `DeviceClass::ArcadeStick`
+/// advertises a fixed, non-sequential `buttons()` list (see `ARCADE_STICK_BUTTONS`), so its bits
+/// need to go through that table to line up with what `collect_axes_and_buttons` advertised.
+fn button_ev_code(device_class: DeviceClass, bit_position: usize) -> EvCode {
+    if device_class == DeviceClass::ArcadeStick {
+        if let Some(code) = ARCADE_STICK_BUTTONS.get(bit_position) {
+            return *code;
+        }
+    }
+    EvCode {
+        kind: EvCodeKind::Button,
+        index: bit_position as u32,
+    }
+}
+
+/// Decomposes a switch (POV hat) position into its `[up, right, down, left]` components.
+///
+/// `position` only ever reports the direction(s) the hardware actually has to give: a two-way
+/// switch can't report `UpRight`, for instance, so there's no need to additionally mask by `kind`
+/// here — doing so previously zeroed out `TwoWay`'s right/left components on the assumption every
+/// two-way switch is a vertical toggle, which silently dropped events from horizontal (left/right)
+/// two-way hardware.
+fn switch_position_to_directions(position: GameControllerSwitchPosition) -> [bool; 4] {
+    match position {
+        GameControllerSwitchPosition::Center => [false, false, false, false],
+        GameControllerSwitchPosition::Up => [true, false, false, false],
+        GameControllerSwitchPosition::UpRight => [true, true, false, false],
+        GameControllerSwitchPosition::Right => [false, true, false, false],
+        GameControllerSwitchPosition::DownRight => [false, true, true, false],
+        GameControllerSwitchPosition::Down => [false, false, true, false],
+        GameControllerSwitchPosition::DownLeft => [false, false, true, true],
+        GameControllerSwitchPosition::Left => [false, false, false, true],
+        GameControllerSwitchPosition::UpLeft => [true, false, false, true],
+        GameControllerSwitchPosition(_) => [false, false, false, false],
+    }
 }
 
 impl Gilrs {
     pub(crate) fn new() -> Result<Self, PlatformError> {
+        Self::new_with_poll_interval(DEFAULT_POLL_INTERVAL)
+    }
+
+    /// Like [`Gilrs::new`], but lets the caller trade latency for CPU usage by choosing how often
+    /// the background thread polls every connected controller, instead of the hard-coded
+    /// [`DEFAULT_POLL_INTERVAL`].
+    pub(crate) fn new_with_poll_interval(poll_interval: Duration) -> Result<Self, PlatformError> {
+        Self::new_with_axis_to_button(poll_interval, AxisToButtonConfig::defaults())
+    }
+
+    /// Like [`Gilrs::new_with_poll_interval`], but lets the caller override the axis-to-button
+    /// hysteresis config (or supply an empty `Vec` to disable axis-to-button synthesis entirely)
+    /// instead of [`AxisToButtonConfig::defaults`].
+    pub(crate) fn new_with_axis_to_button(
+        poll_interval: Duration,
+        axis_to_button: Vec<AxisToButtonConfig>,
+    ) -> Result<Self, PlatformError> {
         let gamepads: Vec<_> = RawGameController::RawGameControllers()
             .map_err(|e| PlatformError::Other(Box::new(e)))?
             .into_iter()
@@ -144,11 +531,15 @@ impl Gilrs {
             .collect();
 
         let (tx, rx) = mpsc::channel();
-        Self::spawn_thread(tx);
+        Self::spawn_thread(tx, poll_interval, axis_to_button);
         Ok(Gilrs { gamepads, rx })
     }
 
-    fn spawn_thread(tx: Sender<WgiEvent>) {
+    fn spawn_thread(
+        tx: Sender<WgiEvent>,
+        poll_interval: Duration,
+        axis_to_button: Vec<AxisToButtonConfig>,
+    ) {
         let added_tx = tx.clone();
         let added_handler: EventHandler<RawGameController> =
             EventHandler::new(move |_, g: &Option<RawGameController>| {
@@ -175,8 +566,19 @@ impl Gilrs {
 
         thread::spawn(move || {
             // To avoid allocating every update, store old and new readings for every controller
-            // and swap their memory
-            let mut readings: Vec<(GamePadReading, GamePadReading)> = Vec::new();
+            // and swap their memory. The specialized projection is cached alongside them since
+            // it only needs to be cast from the raw controller once, `time_anchor` pins one raw
+            // reading timestamp to the wall-clock time it was observed at so every event can be
+            // stamped with the hardware's own clock instead of "whenever the thread noticed", and
+            // the `Vec<bool>` carries each controller's axis-to-button hysteresis state between
+            // polls (index-aligned with `axis_to_button`).
+            let mut readings: Vec<(
+                GamePadReading,
+                GamePadReading,
+                SpecializedController,
+                (u64, SystemTime),
+                Vec<bool>,
+            )> = Vec::new();
             loop {
                 let controllers: Vec<RawGameController> = RawGameController::RawGameControllers()
                     .into_iter()
@@ -184,60 +586,122 @@ impl Gilrs {
                     .collect();
                 for (index, controller) in controllers.iter().enumerate() {
                     if readings.get(index).is_none() {
-                        let reading = GamePadReading::new(controller).unwrap();
-                        readings.push((reading.clone(), reading));
+                        let specialized = SpecializedController::new(controller);
+                        let reading = GamePadReading::new(
+                            controller,
+                            specialized.device_class,
+                            specialized.arcade_stick.as_ref(),
+                            specialized.flight_stick.as_ref(),
+                            specialized.racing_wheel.as_ref(),
+                        )
+                        .unwrap();
+                        let time_anchor = (reading.time, utils::time_now());
+                        let axis_button_states = vec![false; axis_to_button.len()];
+                        readings.push((
+                            reading.clone(),
+                            reading,
+                            specialized,
+                            time_anchor,
+                            axis_button_states,
+                        ));
                     }
-                    let (old_reading, new_reading) = &mut readings[index];
+                    let (old_reading, new_reading, specialized, time_anchor, axis_button_states) =
+                        &mut readings[index];
                     std::mem::swap(old_reading, new_reading);
-                    new_reading.update(controller).unwrap();
+                    new_reading
+                        .update(
+                            controller,
+                            specialized.arcade_stick.as_ref(),
+                            specialized.flight_stick.as_ref(),
+                            specialized.racing_wheel.as_ref(),
+                        )
+                        .unwrap();
                     {
-                        // skip if this is the same reading as the last one.
+                        // Skip if this is the same reading as the last one.
                         if old_reading.time == new_reading.time {
                             continue;
                         }
 
-                        for event_type in old_reading.events_from_differences(new_reading) {
-                            tx.send(WgiEvent::new(controller.clone(), event_type))
-                                .unwrap();
+                        let event_time =
+                            reading_time_to_system_time(*time_anchor, new_reading.time);
+                        let mut event_types = old_reading.events_from_differences(new_reading);
+                        // The axis indices in `axis_to_button` are native gamepad/raw-controller
+                        // indices; the specialized device classes pack unrelated controls (e.g. a
+                        // racing wheel's handbrake/gear) into those same slots, so only synthesize
+                        // buttons for the device classes the indices actually describe.
+                        if matches!(
+                            specialized.device_class,
+                            DeviceClass::RawController | DeviceClass::Gamepad
+                        ) {
+                            event_types.extend(
+                                new_reading
+                                    .axis_to_button_events(&axis_to_button, axis_button_states),
+                            );
+                        }
+                        for event_type in event_types {
+                            tx.send(WgiEvent::with_time(
+                                controller.clone(),
+                                event_type,
+                                event_time,
+                            ))
+                            .unwrap();
                         }
                     };
                 }
-                thread::sleep(Duration::from_millis(EVENT_THREAD_SLEEP_TIME));
+                thread::sleep(poll_interval);
             }
         });
     }
 
     pub(crate) fn next_event(&mut self) -> Option<Event> {
-        self.rx.try_recv().ok().map(|wgi_event: WgiEvent| {
-            // Find the index of the gamepad in our vec or insert it
-            let id = self
-                .gamepads
-                .iter()
-                .position(
-                    |gamepad| match wgi_event.raw_game_controller.NonRoamableId() {
-                        Ok(id) => id == gamepad.non_roamable_id,
-                        _ => false,
-                    },
-                )
-                .unwrap_or_else(|| {
-                    self.gamepads.push(Gamepad::new(
-                        self.gamepads.len() as u32,
-                        wgi_event.raw_game_controller,
-                    ));
-                    self.gamepads.len() - 1
-                });
+        self.rx
+            .try_recv()
+            .ok()
+            .map(|wgi_event| self.event_from_wgi(wgi_event))
+    }
 
-            match wgi_event.event {
-                EventType::Connected => self.gamepads[id].is_connected = true,
-                EventType::Disconnected => self.gamepads[id].is_connected = false,
-                _ => (),
-            }
-            Event {
-                id,
-                event: wgi_event.event,
-                time: wgi_event.time,
-            }
-        })
+    /// Like [`Gilrs::next_event`], but parks the calling thread on the event channel instead of
+    /// polling it, so event-loop-driven callers don't have to busy-spin `next_event`.
+    ///
+    /// `timeout` bounds how long to wait: `None` blocks until an event arrives, `Some(duration)`
+    /// gives up and returns `None` once `duration` elapses with nothing received.
+    pub(crate) fn next_event_blocking(&mut self, timeout: Option<Duration>) -> Option<Event> {
+        let wgi_event = match timeout {
+            Some(timeout) => self.rx.recv_timeout(timeout).ok(),
+            None => self.rx.recv().ok(),
+        };
+        wgi_event.map(|wgi_event| self.event_from_wgi(wgi_event))
+    }
+
+    fn event_from_wgi(&mut self, wgi_event: WgiEvent) -> Event {
+        // Find the index of the gamepad in our vec or insert it
+        let id = self
+            .gamepads
+            .iter()
+            .position(
+                |gamepad| match wgi_event.raw_game_controller.NonRoamableId() {
+                    Ok(id) => id == gamepad.non_roamable_id,
+                    _ => false,
+                },
+            )
+            .unwrap_or_else(|| {
+                self.gamepads.push(Gamepad::new(
+                    self.gamepads.len() as u32,
+                    wgi_event.raw_game_controller,
+                ));
+                self.gamepads.len() - 1
+            });
+
+        match wgi_event.event {
+            EventType::Connected => self.gamepads[id].is_connected = true,
+            EventType::Disconnected => self.gamepads[id].is_connected = false,
+            _ => (),
+        }
+        Event {
+            id,
+            event: wgi_event.event,
+            time: wgi_event.time,
+        }
     }
 
     pub fn gamepad(&self, id: usize) -> Option<&Gamepad> {
@@ -249,6 +713,23 @@ impl Gilrs {
     }
 }
 
+/// Which WGI projection of a [`RawGameController`] best describes this device.
+///
+/// Windows.Gaming.Input exposes the same physical device through several typed views; which
+/// ones successfully cast via `FromGameController` tells us what the device actually is and
+/// which reading shape (and therefore which native [`EvCode`] layout) to use for it.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DeviceClass {
+    /// No specialized projection matched; axes/buttons are anonymous, raw controller indices.
+    RawController,
+    /// Matches the `Windows.Gaming.Input.Gamepad` mapping (standard dual-stick gamepad).
+    Gamepad,
+    ArcadeStick,
+    FlightStick,
+    RacingWheel,
+}
+
 #[derive(Debug)]
 pub struct Gamepad {
     id: u32,
@@ -267,6 +748,16 @@ pub struct Gamepad {
     /// If the controller has a [Gamepad](https://learn.microsoft.com/en-us/uwp/api/windows.gaming.input.gamepad?view=winrt-22621)
     /// mapping, this is used to access the mapped values.
     wgi_gamepad: Option<WgiGamepad>,
+    /// If the controller has an [ArcadeStick](https://learn.microsoft.com/en-us/uwp/api/windows.gaming.input.arcadestick)
+    /// mapping, this is used to access its 8 dedicated face buttons.
+    arcade_stick: Option<ArcadeStick>,
+    /// If the controller has a [FlightStick](https://learn.microsoft.com/en-us/uwp/api/windows.gaming.input.flightstick)
+    /// mapping, this is used to access its pitch/roll/rudder/throttle axes and hat switch.
+    flight_stick: Option<FlightStick>,
+    /// If the controller has a [RacingWheel](https://learn.microsoft.com/en-us/uwp/api/windows.gaming.input.racingwheel)
+    /// mapping, this is used to access its wheel/pedal axes and pattern-shifter gear.
+    racing_wheel: Option<RacingWheel>,
+    device_class: DeviceClass,
     axes: Vec<EvCode>,
     buttons: Vec<EvCode>,
 }
@@ -277,8 +768,17 @@ impl Gamepad {
 
         let non_roamable_id = raw_game_controller.NonRoamableId().unwrap();
 
-        // See if we can cast this to a windows definition of a gamepad
+        // Try every WGI projection of this controller. Most devices only match one of these in
+        // addition to the raw controller; where more than one matches, prefer the most specific
+        // projection since it's the one with the richer reading shape.
         let wgi_gamepad = WgiGamepad::FromGameController(&raw_game_controller).ok();
+        let SpecializedController {
+            device_class,
+            arcade_stick,
+            flight_stick,
+            racing_wheel,
+        } = SpecializedController::new(&raw_game_controller);
+
         let name = match raw_game_controller.DisplayName() {
             Ok(hstring) => hstring.to_string_lossy(),
             Err(_) => "unknown".to_string(),
@@ -319,6 +819,10 @@ impl Gamepad {
             raw_game_controller,
             non_roamable_id,
             wgi_gamepad,
+            arcade_stick,
+            flight_stick,
+            racing_wheel,
+            device_class,
             axes: Vec::new(),
             buttons: Vec::new(),
         };
@@ -340,6 +844,12 @@ impl Gamepad {
         self.is_connected
     }
 
+    /// Which WGI projection (plain gamepad, arcade stick, flight stick, racing wheel, or none)
+    /// this device was detected as, so callers can pick an appropriate mapping.
+    pub fn device_class(&self) -> DeviceClass {
+        self.device_class
+    }
+
     pub fn power_info(&self) -> PowerInfo {
         self.power_info_err().unwrap_or(PowerInfo::Unknown)
     }
@@ -382,7 +892,11 @@ impl Gamepad {
     }
 
     pub fn ff_device(&self) -> Option<FfDevice> {
-        Some(FfDevice::new(self.id, self.wgi_gamepad.clone()))
+        Some(FfDevice::new(
+            self.id,
+            self.raw_game_controller.clone(),
+            self.wgi_gamepad.clone(),
+        ))
     }
 
     pub fn buttons(&self) -> &[EvCode] {
@@ -393,6 +907,19 @@ impl Gamepad {
         &self.axes
     }
 
+    /// Returns the semantic label Windows assigns to `code`, e.g. `ButtonLabel::XboxY` on an
+    /// Xbox pad and `ButtonLabel::PlayStationTriangle` for the same physical position on a
+    /// DualShock, so callers can render the correct glyph without a per-vendor lookup table.
+    ///
+    /// Returns `None` for axes/switches, or when WGI doesn't report a label for this control.
+    pub fn button_label(&self, code: EvCode) -> Option<ButtonLabel> {
+        if code.kind != EvCodeKind::Button {
+            return None;
+        }
+        let label = self.raw_game_controller.ButtonLabel(code.index).ok()?;
+        ButtonLabel::from_wgi(label)
+    }
+
     pub(crate) fn axis_info(&self, _nec: EvCode) -> Option<&AxisInfo> {
         Some(&AxisInfo {
             min: i16::MIN as i32,
@@ -402,11 +929,69 @@ impl Gamepad {
     }
 
     fn collect_axes_and_buttons(&mut self) {
+        // Devices with a specialized WGI projection have a reading shape that doesn't line up
+        // with the raw controller's anonymous axis/button indices, so give them the native
+        // `EvCode`s that actually describe their controls instead.
+        match self.device_class {
+            DeviceClass::ArcadeStick => {
+                self.buttons = ARCADE_STICK_BUTTONS.to_vec();
+                self.axes = Vec::new();
+                return;
+            }
+            DeviceClass::FlightStick => {
+                use native_ev_codes::*;
+                self.buttons = (0..(self.raw_game_controller.ButtonCount().unwrap_or(0) as u32))
+                    .map(|index| EvCode {
+                        kind: EvCodeKind::Button,
+                        index,
+                    })
+                    // `update_with`/`events_from_differences` also diff the flight stick's single
+                    // `HatSwitch()` the same way the generic raw-controller/gamepad branch below
+                    // diffs switches, so advertise its up/right/down/left EvCodes here too.
+                    .chain((0..4u32).map(|direction| EvCode {
+                        kind: EvCodeKind::Switch,
+                        index: direction,
+                    }))
+                    .collect();
+                self.axes = vec![AXIS_ROLL, AXIS_PITCH, AXIS_RUDDER, AXIS_THROTTLE];
+                return;
+            }
+            DeviceClass::RacingWheel => {
+                use native_ev_codes::*;
+                self.buttons = (0..(self.raw_game_controller.ButtonCount().unwrap_or(0) as u32))
+                    .map(|index| EvCode {
+                        kind: EvCodeKind::Button,
+                        index,
+                    })
+                    .collect();
+                self.axes = vec![
+                    AXIS_WHEEL,
+                    AXIS_THROTTLE,
+                    AXIS_BRAKE,
+                    AXIS_CLUTCH,
+                    AXIS_HANDBRAKE,
+                    AXIS_GEAR,
+                ];
+                return;
+            }
+            DeviceClass::RawController | DeviceClass::Gamepad => {}
+        }
+
         self.buttons = (0..(self.raw_game_controller.ButtonCount().unwrap() as u32))
             .map(|index| EvCode {
                 kind: EvCodeKind::Button,
                 index,
             })
+            .chain(
+                (0..(self.raw_game_controller.SwitchCount().unwrap() as u32)).flat_map(
+                    |switch_index| {
+                        (0..4u32).map(move |direction| EvCode {
+                            kind: EvCodeKind::Switch,
+                            index: switch_index * 4 + direction,
+                        })
+                    },
+                ),
+            )
             .collect();
         self.axes = (0..(self.raw_game_controller.AxisCount().unwrap() as u32))
             .map(|index| EvCode {
@@ -455,6 +1040,85 @@ impl Display for EvCode {
     }
 }
 
+/// The semantic meaning Windows.Gaming.Input assigns to a physical control, independent of
+/// which [`EvCode`] index it happens to be wired to on this gamepad.
+///
+/// WGI normalizes every controller to the Xbox layout, but still knows the original vendor
+/// glyph (e.g. a DualShock's Cross/Circle/Square/Triangle) via `GameControllerButtonLabel`.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ButtonLabel {
+    XboxA,
+    XboxB,
+    XboxX,
+    XboxY,
+    PlayStationCross,
+    PlayStationCircle,
+    PlayStationSquare,
+    PlayStationTriangle,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    BumperLeft,
+    BumperRight,
+    TriggerLeft,
+    TriggerRight,
+    Menu,
+    View,
+    Paddle1,
+    Paddle2,
+    Paddle3,
+    Paddle4,
+}
+
+impl ButtonLabel {
+    fn from_wgi(label: GameControllerButtonLabel) -> Option<Self> {
+        Some(match label {
+            GameControllerButtonLabel::XboxA => ButtonLabel::XboxA,
+            GameControllerButtonLabel::XboxB => ButtonLabel::XboxB,
+            GameControllerButtonLabel::XboxX => ButtonLabel::XboxX,
+            GameControllerButtonLabel::XboxY => ButtonLabel::XboxY,
+            GameControllerButtonLabel::Cross => ButtonLabel::PlayStationCross,
+            GameControllerButtonLabel::Circle => ButtonLabel::PlayStationCircle,
+            GameControllerButtonLabel::Square => ButtonLabel::PlayStationSquare,
+            GameControllerButtonLabel::Triangle => ButtonLabel::PlayStationTriangle,
+            GameControllerButtonLabel::XboxUp | GameControllerButtonLabel::Up => {
+                ButtonLabel::DPadUp
+            }
+            GameControllerButtonLabel::XboxDown | GameControllerButtonLabel::Down => {
+                ButtonLabel::DPadDown
+            }
+            GameControllerButtonLabel::XboxLeft | GameControllerButtonLabel::Left => {
+                ButtonLabel::DPadLeft
+            }
+            GameControllerButtonLabel::XboxRight | GameControllerButtonLabel::Right => {
+                ButtonLabel::DPadRight
+            }
+            GameControllerButtonLabel::XboxLeftBumper | GameControllerButtonLabel::BumperLeft => {
+                ButtonLabel::BumperLeft
+            }
+            GameControllerButtonLabel::XboxRightBumper
+            | GameControllerButtonLabel::BumperRight => ButtonLabel::BumperRight,
+            GameControllerButtonLabel::XboxLeftTrigger
+            | GameControllerButtonLabel::TriggerLeft => ButtonLabel::TriggerLeft,
+            GameControllerButtonLabel::XboxRightTrigger
+            | GameControllerButtonLabel::TriggerRight => ButtonLabel::TriggerRight,
+            GameControllerButtonLabel::XboxMenu | GameControllerButtonLabel::Menu => {
+                ButtonLabel::Menu
+            }
+            GameControllerButtonLabel::XboxView | GameControllerButtonLabel::View => {
+                ButtonLabel::View
+            }
+            GameControllerButtonLabel::XboxPaddle1 => ButtonLabel::Paddle1,
+            GameControllerButtonLabel::XboxPaddle2 => ButtonLabel::Paddle2,
+            GameControllerButtonLabel::XboxPaddle3 => ButtonLabel::Paddle3,
+            GameControllerButtonLabel::XboxPaddle4 => ButtonLabel::Paddle4,
+            GameControllerButtonLabel::None | GameControllerButtonLabel(_) => return None,
+        })
+    }
+}
+
 pub mod native_ev_codes {
     use super::{EvCode, EvCodeKind};
 
@@ -507,6 +1171,45 @@ pub mod native_ev_codes {
         index: 11,
     };
 
+    // Axes used by the specialized flight-stick/racing-wheel `DeviceClass`es, whose readings
+    // don't come from the same anonymous axis array as a raw controller or plain gamepad.
+    pub const AXIS_WHEEL: EvCode = EvCode {
+        kind: EvCodeKind::Axis,
+        index: 12,
+    };
+    pub const AXIS_THROTTLE: EvCode = EvCode {
+        kind: EvCodeKind::Axis,
+        index: 13,
+    };
+    pub const AXIS_BRAKE: EvCode = EvCode {
+        kind: EvCodeKind::Axis,
+        index: 14,
+    };
+    pub const AXIS_CLUTCH: EvCode = EvCode {
+        kind: EvCodeKind::Axis,
+        index: 15,
+    };
+    pub const AXIS_HANDBRAKE: EvCode = EvCode {
+        kind: EvCodeKind::Axis,
+        index: 16,
+    };
+    pub const AXIS_GEAR: EvCode = EvCode {
+        kind: EvCodeKind::Axis,
+        index: 17,
+    };
+    pub const AXIS_ROLL: EvCode = EvCode {
+        kind: EvCodeKind::Axis,
+        index: 18,
+    };
+    pub const AXIS_PITCH: EvCode = EvCode {
+        kind: EvCodeKind::Axis,
+        index: 19,
+    };
+    pub const AXIS_RUDDER: EvCode = EvCode {
+        kind: EvCodeKind::Axis,
+        index: 20,
+    };
+
     pub const BTN_SOUTH: EvCode = EvCode {
         kind: EvCodeKind::Button,
         index: 0,
@@ -548,21 +1251,26 @@ pub mod native_ev_codes {
         index: 9,
     };
 
-    pub const BTN_DPAD_UP: EvCode = EvCode {
-        kind: EvCodeKind::Button,
-        index: 10,
+    // The D-pad/POV hat is decomposed into up/right/down/left `EvCodeKind::Switch` events by
+    // `events_from_differences` (switch index * 4 + direction), not reported as plain buttons, so
+    // these live in the `Switch` space rather than colliding with real button indices. They cover
+    // switch 0, the common single-D-pad case; additional switches are addressable directly as
+    // `EvCode{kind: Switch, index: switch_index * 4 + direction}`.
+    pub const SWITCH_DPAD_UP: EvCode = EvCode {
+        kind: EvCodeKind::Switch,
+        index: 0,
     };
-    pub const BTN_DPAD_RIGHT: EvCode = EvCode {
-        kind: EvCodeKind::Button,
-        index: 11,
+    pub const SWITCH_DPAD_RIGHT: EvCode = EvCode {
+        kind: EvCodeKind::Switch,
+        index: 1,
     };
-    pub const BTN_DPAD_DOWN: EvCode = EvCode {
-        kind: EvCodeKind::Button,
-        index: 12,
+    pub const SWITCH_DPAD_DOWN: EvCode = EvCode {
+        kind: EvCodeKind::Switch,
+        index: 2,
     };
-    pub const BTN_DPAD_LEFT: EvCode = EvCode {
-        kind: EvCodeKind::Button,
-        index: 13,
+    pub const SWITCH_DPAD_LEFT: EvCode = EvCode {
+        kind: EvCodeKind::Switch,
+        index: 3,
     };
 
     pub const BTN_MODE: EvCode = EvCode {
@@ -587,3 +1295,136 @@ pub mod native_ev_codes {
         index: 18,
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn switch_position_to_directions_diagonals_set_both_components() {
+        assert_eq!(
+            switch_position_to_directions(GameControllerSwitchPosition::UpRight),
+            [true, true, false, false]
+        );
+        assert_eq!(
+            switch_position_to_directions(GameControllerSwitchPosition::DownRight),
+            [false, true, true, false]
+        );
+        assert_eq!(
+            switch_position_to_directions(GameControllerSwitchPosition::DownLeft),
+            [false, false, true, true]
+        );
+        assert_eq!(
+            switch_position_to_directions(GameControllerSwitchPosition::UpLeft),
+            [true, false, false, true]
+        );
+    }
+
+    #[test]
+    fn switch_position_to_directions_two_way_left_right_is_not_masked() {
+        // A real 2-way switch only ever reports Center/Left/Right (or Center/Up/Down), never a
+        // diagonal; it should come through exactly like any other `Left`/`Right` report, with no
+        // component zeroed out on the assumption every 2-way switch is vertical.
+        assert_eq!(
+            switch_position_to_directions(GameControllerSwitchPosition::Left),
+            [false, false, false, true]
+        );
+        assert_eq!(
+            switch_position_to_directions(GameControllerSwitchPosition::Right),
+            [false, true, false, false]
+        );
+    }
+
+    #[test]
+    fn switch_position_to_directions_center_is_all_false() {
+        assert_eq!(
+            switch_position_to_directions(GameControllerSwitchPosition::Center),
+            [false, false, false, false]
+        );
+    }
+
+    fn reading_with_axis(axis_value: f64) -> GamePadReading {
+        GamePadReading {
+            axes: vec![axis_value],
+            buttons: Vec::new(),
+            switches: Vec::new(),
+            time: 0,
+            device_class: DeviceClass::RawController,
+            button_count: 0,
+        }
+    }
+
+    fn lt2_config() -> Vec<AxisToButtonConfig> {
+        vec![AxisToButtonConfig::new(
+            EvCode {
+                kind: EvCodeKind::Axis,
+                index: 0,
+            },
+            EvCode {
+                kind: EvCodeKind::Button,
+                index: 0,
+            },
+            0.75,
+            0.65,
+        )]
+    }
+
+    #[test]
+    fn axis_to_button_events_presses_at_press_threshold() {
+        let axis_to_button = lt2_config();
+        let mut held = [false];
+        let events = reading_with_axis(0.75).axis_to_button_events(&axis_to_button, &mut held);
+        assert!(matches!(events[..], [EventType::ButtonPressed(_)]));
+        assert!(held[0]);
+    }
+
+    #[test]
+    fn axis_to_button_events_does_not_release_between_thresholds() {
+        // Once held, the hysteresis gap (0.65..0.75) must not release the button: only dropping
+        // below release_threshold should.
+        let axis_to_button = lt2_config();
+        let mut held = [true];
+        let events = reading_with_axis(0.7).axis_to_button_events(&axis_to_button, &mut held);
+        assert!(events.is_empty());
+        assert!(held[0]);
+    }
+
+    #[test]
+    fn axis_to_button_events_releases_below_release_threshold() {
+        let axis_to_button = lt2_config();
+        let mut held = [true];
+        let events = reading_with_axis(0.6).axis_to_button_events(&axis_to_button, &mut held);
+        assert!(matches!(events[..], [EventType::ButtonReleased(_)]));
+        assert!(!held[0]);
+    }
+
+    #[test]
+    fn scale_axis_value_unsigned_unit_centers_and_doubles() {
+        assert_eq!(
+            scale_axis_value(DeviceClass::Gamepad, 0, 0.0),
+            -(u16::MAX as i32)
+        );
+        assert_eq!(scale_axis_value(DeviceClass::Gamepad, 0, 0.5), 0);
+        assert_eq!(
+            scale_axis_value(DeviceClass::Gamepad, 0, 1.0),
+            u16::MAX as i32
+        );
+    }
+
+    #[test]
+    fn scale_axis_value_signed_unit_is_not_recentered() {
+        // FlightStick axis 0 (Roll) is already signed -1.0..1.0, unlike the generic
+        // raw-controller/gamepad 0.0..1.0 range.
+        assert_eq!(scale_axis_value(DeviceClass::FlightStick, 0, 0.0), 0);
+        assert_eq!(
+            scale_axis_value(DeviceClass::FlightStick, 0, 1.0),
+            u16::MAX as i32
+        );
+    }
+
+    #[test]
+    fn scale_axis_value_raw_passes_through_unscaled() {
+        // RacingWheel axis 5 (PatternShifterGear) is a gear number, not a normalized analog value.
+        assert_eq!(scale_axis_value(DeviceClass::RacingWheel, 5, 3.0), 3);
+    }
+}